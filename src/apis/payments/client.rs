@@ -0,0 +1,77 @@
+use crate::{
+    idempotency::{IdempotencyKey, IDEMPOTENCY_KEY_HEADER},
+    Error,
+};
+use anyhow::anyhow;
+
+use super::model::{CreatePaymentRequest, CreatePaymentResponse, Payment};
+
+/// Client for the payments API.
+#[derive(Debug, Clone)]
+pub struct PaymentsApi {
+    pub(crate) client: reqwest::Client,
+    pub(crate) base_url: reqwest::Url,
+}
+
+impl PaymentsApi {
+    /// Creates a payment.
+    ///
+    /// `idempotency_key` is sent as the `Idempotency-Key` header so a retried
+    /// call returns the original payment instead of creating a duplicate. If
+    /// none is supplied, a fresh one is generated.
+    pub async fn create(
+        &self,
+        request: &CreatePaymentRequest,
+        idempotency_key: Option<IdempotencyKey>,
+    ) -> Result<CreatePaymentResponse, Error> {
+        let idempotency_key = idempotency_key.unwrap_or_default();
+        let url = self
+            .base_url
+            .join("payments")
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+
+        let mut response: CreatePaymentResponse = self
+            .client
+            .post(url)
+            .header(IDEMPOTENCY_KEY_HEADER, idempotency_key.to_string())
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))?
+            .error_for_status()
+            .map_err(|e| Error::Other(anyhow!(e)))?
+            .json()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+
+        response.idempotency_key = idempotency_key;
+        Ok(response)
+    }
+
+    /// Fetches a single payment by id, returning `None` if no such payment exists.
+    pub async fn get_by_id(&self, id: &str) -> Result<Option<Payment>, Error> {
+        let url = self
+            .base_url
+            .join(&format!("payments/{id}"))
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        response
+            .error_for_status()
+            .map_err(|e| Error::Other(anyhow!(e)))?
+            .json()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))
+            .map(Some)
+    }
+}
@@ -0,0 +1,4 @@
+mod client;
+pub mod model;
+
+pub use client::PaymentsApi;
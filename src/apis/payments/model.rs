@@ -1,19 +1,25 @@
-use crate::{apis::auth::Token, pollable::IsInTerminalState, Error, Pollable, TrueLayerClient};
+use crate::{
+    apis::auth::Token, idempotency::IdempotencyKey, pollable::IsInTerminalState, Error, Pollable,
+    TrueLayerClient,
+};
 use anyhow::anyhow;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
     fmt::{Display, Formatter},
 };
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Builder)]
+#[builder(setter(into))]
 pub struct CreatePaymentRequest {
     pub amount_in_minor: u64,
     pub currency: Currency,
     pub payment_method: PaymentMethod,
     pub user: CreatePaymentUserRequest,
+    #[builder(setter(strip_option, into), default)]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -35,6 +41,12 @@ pub struct CreatePaymentResponse {
     pub id: String,
     pub resource_token: Token,
     pub user: CreatePaymentUserResponse,
+    /// The idempotency key the create request was sent with.
+    ///
+    /// Not part of the API response body: populated by the client so a
+    /// caller retrying the creation of this payment can reuse it.
+    #[serde(skip)]
+    pub idempotency_key: IdempotencyKey,
 }
 
 #[async_trait]
@@ -179,6 +191,67 @@ pub enum Beneficiary {
     },
 }
 
+impl Beneficiary {
+    /// Starts building a [`Beneficiary::ExternalAccount`] variant.
+    pub fn external_account() -> ExternalAccountBuilder {
+        ExternalAccountBuilder::default()
+    }
+}
+
+/// Builder for [`Beneficiary::ExternalAccount`].
+#[derive(Debug, Clone, Default)]
+pub struct ExternalAccountBuilder {
+    account_holder_name: Option<String>,
+    account_identifier: Option<AccountIdentifier>,
+    reference: Option<String>,
+}
+
+impl ExternalAccountBuilder {
+    pub fn account_holder_name(mut self, account_holder_name: impl Into<String>) -> Self {
+        self.account_holder_name = Some(account_holder_name.into());
+        self
+    }
+
+    pub fn account_identifier(mut self, account_identifier: AccountIdentifier) -> Self {
+        self.account_identifier = Some(account_identifier);
+        self
+    }
+
+    pub fn reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    pub fn build(self) -> Result<Beneficiary, ExternalAccountBuilderError> {
+        let account_holder_name = self
+            .account_holder_name
+            .ok_or(ExternalAccountBuilderError::UninitializedField("account_holder_name"))?;
+        let account_identifier = self
+            .account_identifier
+            .ok_or(ExternalAccountBuilderError::UninitializedField("account_identifier"))?;
+        let reference = self
+            .reference
+            .ok_or(ExternalAccountBuilderError::UninitializedField("reference"))?;
+        if reference.is_empty() {
+            return Err(ExternalAccountBuilderError::EmptyReference);
+        }
+
+        Ok(Beneficiary::ExternalAccount {
+            account_holder_name,
+            account_identifier,
+            reference,
+        })
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ExternalAccountBuilderError {
+    #[error("`{0}` must be set")]
+    UninitializedField(&'static str),
+    #[error("reference must not be empty")]
+    EmptyReference,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum AccountIdentifier {
@@ -216,13 +289,51 @@ pub enum ProviderSelection {
     },
 }
 
+impl ProviderSelection {
+    /// Starts building a [`ProviderSelection::UserSelected`] variant.
+    pub fn user_selected() -> UserSelectedBuilder {
+        UserSelectedBuilder::default()
+    }
+}
+
+/// Builder for [`ProviderSelection::UserSelected`].
+///
+/// `derive_builder` only targets structs, so this variant gets a hand-written
+/// builder. Its setters take and return the builder by value, and `build`
+/// cannot fail.
+#[derive(Debug, Clone, Default)]
+pub struct UserSelectedBuilder {
+    filter: Option<ProviderFilter>,
+    preferred_scheme_ids: Option<Vec<String>>,
+}
+
+impl UserSelectedBuilder {
+    pub fn filter(mut self, filter: ProviderFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn preferred_scheme_ids(mut self, preferred_scheme_ids: Vec<String>) -> Self {
+        self.preferred_scheme_ids = Some(preferred_scheme_ids);
+        self
+    }
+
+    pub fn build(self) -> ProviderSelection {
+        ProviderSelection::UserSelected {
+            filter: self.filter,
+            preferred_scheme_ids: self.preferred_scheme_ids,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct Remitter {
     pub account_holder_name: Option<String>,
     pub account_identifier: Option<AccountIdentifier>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default, build_fn(validate = "Self::validate"))]
 pub struct ProviderFilter {
     pub countries: Option<Vec<CountryCode>>,
     pub release_channel: Option<ReleaseChannel>,
@@ -231,6 +342,26 @@ pub struct ProviderFilter {
     pub excludes: Option<ProviderFilterExcludes>,
 }
 
+impl ProviderFilterBuilder {
+    /// Rejects a filter that both includes and excludes the same provider id.
+    fn validate(&self) -> Result<(), String> {
+        let Some(Some(provider_ids)) = &self.provider_ids else {
+            return Ok(());
+        };
+        let Some(Some(excludes)) = &self.excludes else {
+            return Ok(());
+        };
+        let Some(excluded_ids) = &excludes.provider_ids else {
+            return Ok(());
+        };
+
+        if provider_ids.iter().any(|id| excluded_ids.contains(id)) {
+            return Err("a provider id cannot be both included and excluded".to_string());
+        }
+        Ok(())
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum CountryCode {
@@ -484,3 +615,84 @@ pub struct SubmitProviderReturnParametersResponse {
 pub enum SubmitProviderReturnParametersResponseResource {
     Payment { payment_id: String },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provider_filter_builder_rejects_overlapping_include_and_exclude() {
+        let result = ProviderFilterBuilder::default()
+            .provider_ids(vec!["provider-a".to_string(), "provider-b".to_string()])
+            .excludes(ProviderFilterExcludes {
+                provider_ids: Some(vec!["provider-b".to_string()]),
+            })
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn provider_filter_builder_allows_disjoint_include_and_exclude() {
+        let result = ProviderFilterBuilder::default()
+            .provider_ids(vec!["provider-a".to_string()])
+            .excludes(ProviderFilterExcludes {
+                provider_ids: Some(vec!["provider-b".to_string()]),
+            })
+            .build();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn external_account_builder_rejects_an_empty_reference() {
+        let result = Beneficiary::external_account()
+            .account_holder_name("Jane Doe")
+            .account_identifier(AccountIdentifier::Iban {
+                iban: "GB33BUKB20201555555555".to_string(),
+            })
+            .reference("")
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(ExternalAccountBuilderError::EmptyReference)
+        ));
+    }
+
+    #[test]
+    fn external_account_builder_rejects_a_missing_field() {
+        let result = Beneficiary::external_account()
+            .account_holder_name("Jane Doe")
+            .reference("a reference")
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(ExternalAccountBuilderError::UninitializedField("account_identifier"))
+        ));
+    }
+
+    #[test]
+    fn external_account_builder_builds_a_valid_beneficiary() {
+        let beneficiary = Beneficiary::external_account()
+            .account_holder_name("Jane Doe")
+            .account_identifier(AccountIdentifier::Iban {
+                iban: "GB33BUKB20201555555555".to_string(),
+            })
+            .reference("a reference")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            beneficiary,
+            Beneficiary::ExternalAccount {
+                account_holder_name: "Jane Doe".to_string(),
+                account_identifier: AccountIdentifier::Iban {
+                    iban: "GB33BUKB20201555555555".to_string(),
+                },
+                reference: "a reference".to_string(),
+            }
+        );
+    }
+}
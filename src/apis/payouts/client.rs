@@ -0,0 +1,60 @@
+use crate::Error;
+use anyhow::anyhow;
+
+use super::model::{CreatePayoutRequest, CreatePayoutResponse, Payout};
+
+/// Client for the payouts API.
+#[derive(Debug, Clone)]
+pub struct PayoutsApi {
+    pub(crate) client: reqwest::Client,
+    pub(crate) base_url: reqwest::Url,
+}
+
+impl PayoutsApi {
+    /// Pays funds out of a merchant account.
+    pub async fn create(&self, request: &CreatePayoutRequest) -> Result<CreatePayoutResponse, Error> {
+        let url = self
+            .base_url
+            .join("payouts")
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+
+        self.client
+            .post(url)
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))?
+            .error_for_status()
+            .map_err(|e| Error::Other(anyhow!(e)))?
+            .json()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))
+    }
+
+    /// Fetches a single payout by id, returning `None` if no such payout exists.
+    pub async fn get_by_id(&self, id: &str) -> Result<Option<Payout>, Error> {
+        let url = self
+            .base_url
+            .join(&format!("payouts/{id}"))
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        response
+            .error_for_status()
+            .map_err(|e| Error::Other(anyhow!(e)))?
+            .json()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))
+            .map(Some)
+    }
+}
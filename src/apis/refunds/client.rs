@@ -0,0 +1,89 @@
+use crate::{
+    idempotency::{IdempotencyKey, IDEMPOTENCY_KEY_HEADER},
+    Error,
+};
+use anyhow::anyhow;
+
+use super::model::{CreateRefundRequest, CreateRefundResponse, Refund};
+
+/// Client for the refunds API.
+///
+/// Refunds are always raised, and later fetched, in the context of the
+/// payment they belong to.
+#[derive(Debug, Clone)]
+pub struct RefundsApi {
+    pub(crate) client: reqwest::Client,
+    pub(crate) base_url: reqwest::Url,
+}
+
+impl RefundsApi {
+    /// Refunds a settled payment.
+    ///
+    /// `idempotency_key` is sent as the `Idempotency-Key` header so a retried
+    /// call returns the original refund instead of creating a duplicate. If
+    /// none is supplied, a fresh one is generated.
+    pub async fn create(
+        &self,
+        payment_id: &str,
+        request: &CreateRefundRequest,
+        idempotency_key: Option<IdempotencyKey>,
+    ) -> Result<CreateRefundResponse, Error> {
+        let idempotency_key = idempotency_key.unwrap_or_default();
+        let url = self
+            .base_url
+            .join(&format!("payments/{payment_id}/refunds"))
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+
+        let mut response: CreateRefundResponse = self
+            .client
+            .post(url)
+            .header(IDEMPOTENCY_KEY_HEADER, idempotency_key.to_string())
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))?
+            .error_for_status()
+            .map_err(|e| Error::Other(anyhow!(e)))?
+            .json()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+
+        response.payment_id = payment_id.to_string();
+        response.idempotency_key = idempotency_key;
+        Ok(response)
+    }
+
+    /// Fetches a single refund of a payment by id, returning `None` if no
+    /// such refund exists.
+    pub async fn get_by_id(
+        &self,
+        payment_id: &str,
+        refund_id: &str,
+    ) -> Result<Option<Refund>, Error> {
+        let url = self
+            .base_url
+            .join(&format!("payments/{payment_id}/refunds/{refund_id}"))
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let mut refund: Refund = response
+            .error_for_status()
+            .map_err(|e| Error::Other(anyhow!(e)))?
+            .json()
+            .await
+            .map_err(|e| Error::Other(anyhow!(e)))?;
+        refund.payment_id = payment_id.to_string();
+
+        Ok(Some(refund))
+    }
+}
@@ -0,0 +1,5 @@
+mod client;
+mod model;
+
+pub use client::RefundsApi;
+pub use model::*;
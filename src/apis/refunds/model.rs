@@ -0,0 +1,103 @@
+use crate::{
+    apis::payments::model::Currency, idempotency::IdempotencyKey, pollable::IsInTerminalState,
+    Error, Pollable, TrueLayerClient,
+};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Request to refund a settled payment.
+///
+/// Omitting `amount_in_minor` refunds the payment in full.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateRefundRequest {
+    pub amount_in_minor: Option<u64>,
+    pub reference: String,
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateRefundResponse {
+    pub id: String,
+    /// The id of the payment this refund was raised against.
+    ///
+    /// Not present in the API response body: populated by the client after
+    /// the request succeeds so the response can be polled via [`Pollable`].
+    #[serde(skip)]
+    pub(crate) payment_id: String,
+    /// The idempotency key the create request was sent with.
+    ///
+    /// Not part of the API response body: populated by the client so a
+    /// caller retrying the creation of this refund can reuse it.
+    #[serde(skip)]
+    pub idempotency_key: IdempotencyKey,
+}
+
+#[async_trait]
+impl Pollable for CreateRefundResponse {
+    type Output = Refund;
+
+    async fn poll_once(&self, tl: &TrueLayerClient) -> Result<Self::Output, Error> {
+        tl.refunds
+            .get_by_id(&self.payment_id, &self.id)
+            .await
+            .transpose()
+            .unwrap_or_else(|| Err(Error::Other(anyhow!("Refund returned 404 while polling"))))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct Refund {
+    pub id: String,
+    pub amount_in_minor: u64,
+    pub currency: Currency,
+    pub reference: String,
+    pub created_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub status: RefundStatus,
+    /// The id of the payment this refund was raised against.
+    ///
+    /// Not present in the API response body: populated by the client from
+    /// the request path so the resource can be polled via [`Pollable`].
+    #[serde(skip)]
+    pub(crate) payment_id: String,
+}
+
+#[async_trait]
+impl Pollable for Refund {
+    type Output = Refund;
+
+    async fn poll_once(&self, tl: &TrueLayerClient) -> Result<Self::Output, Error> {
+        tl.refunds
+            .get_by_id(&self.payment_id, &self.id)
+            .await
+            .transpose()
+            .unwrap_or_else(|| Err(Error::Other(anyhow!("Refund returned 404 while polling"))))
+    }
+}
+
+impl IsInTerminalState for Refund {
+    /// A refund is considered to be in a terminal state if it is `Executed` or `Failed`.
+    fn is_in_terminal_state(&self) -> bool {
+        matches!(
+            self.status,
+            RefundStatus::Executed { .. } | RefundStatus::Failed { .. }
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RefundStatus {
+    Pending,
+    Authorized,
+    Executed {
+        executed_at: DateTime<Utc>,
+    },
+    Failed {
+        failed_at: DateTime<Utc>,
+        failure_reason: String,
+    },
+}
@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The header TrueLayer reads an [`IdempotencyKey`] from on create requests.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// A client-supplied key that makes payment and refund creation idempotent.
+///
+/// Sending the same key on a retried create request guarantees TrueLayer
+/// returns the original resource instead of creating a duplicate. Pass one
+/// explicitly to `PaymentsApi::create`/`RefundsApi::create` to control retries
+/// yourself, or leave it unset: a fresh key from [`IdempotencyKey::generate`]
+/// is sent and returned on the response's `idempotency_key` field. Each call
+/// made without a key gets a new one, so such calls are not deduplicated
+/// against each other.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    /// Generates a new random idempotency key.
+    pub fn generate() -> Self {
+        Self(Uuid::new_v4().to_string())
+    }
+}
+
+impl Default for IdempotencyKey {
+    fn default() -> Self {
+        Self::generate()
+    }
+}
+
+impl Display for IdempotencyKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for IdempotencyKey {
+    fn from(key: String) -> Self {
+        Self(key)
+    }
+}
+
+impl From<&str> for IdempotencyKey {
+    fn from(key: &str) -> Self {
+        Self(key.to_string())
+    }
+}
@@ -0,0 +1,211 @@
+use crate::Error;
+use anyhow::anyhow;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use p521::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The only `tl_version` this client knows how to verify.
+const SUPPORTED_TL_VERSION: &str = "2";
+
+/// The detached JWS header TrueLayer signs the webhook body with.
+#[derive(Deserialize, Debug, Clone)]
+pub(super) struct JwsHeader {
+    pub alg: String,
+    pub kid: String,
+    pub tl_version: String,
+    pub tl_headers: String,
+}
+
+/// A `Tl-Signature` header, split into its protected header and signature.
+pub(super) struct DetachedJws {
+    pub header: JwsHeader,
+    pub header_b64: String,
+    pub signature: Vec<u8>,
+}
+
+impl DetachedJws {
+    /// Parses a detached JWS of the form `<header>..<signature>`.
+    pub fn parse(signature_header: &str) -> Result<Self, Error> {
+        let mut parts = signature_header.split('.');
+        let header_b64 = parts
+            .next()
+            .ok_or_else(|| Error::Other(anyhow!("Tl-Signature is missing its header segment")))?;
+        // The payload segment is intentionally empty: the JWS is detached and the
+        // real payload is reconstructed from the request instead.
+        let payload = parts
+            .next()
+            .ok_or_else(|| Error::Other(anyhow!("Tl-Signature is missing its payload segment")))?;
+        if !payload.is_empty() {
+            return Err(Error::Other(anyhow!(
+                "Tl-Signature payload segment must be empty for a detached JWS"
+            )));
+        }
+        let signature_b64 = parts
+            .next()
+            .ok_or_else(|| Error::Other(anyhow!("Tl-Signature is missing its signature segment")))?;
+        if parts.next().is_some() {
+            return Err(Error::Other(anyhow!("Tl-Signature has too many segments")));
+        }
+
+        let header_json = URL_SAFE_NO_PAD
+            .decode(header_b64)
+            .map_err(|e| Error::Other(anyhow!("Tl-Signature header is not valid base64: {e}")))?;
+        let header: JwsHeader = serde_json::from_slice(&header_json)
+            .map_err(|e| Error::Other(anyhow!("Tl-Signature header is not valid JSON: {e}")))?;
+
+        if header.alg != "ES512" {
+            return Err(Error::Other(anyhow!(
+                "unsupported Tl-Signature algorithm: {}",
+                header.alg
+            )));
+        }
+        if header.tl_version != SUPPORTED_TL_VERSION {
+            return Err(Error::Other(anyhow!(
+                "unsupported Tl-Signature version: {}",
+                header.tl_version
+            )));
+        }
+
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature_b64)
+            .map_err(|e| Error::Other(anyhow!("Tl-Signature signature is not valid base64: {e}")))?;
+
+        Ok(Self {
+            header,
+            header_b64: header_b64.to_string(),
+            signature,
+        })
+    }
+
+    /// Rebuilds the signing input: the protected header, joined to the
+    /// normalised method, path, selected headers and body.
+    pub fn signing_input(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &HashMap<String, String>,
+        body: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let mut payload = format!("{}\n{}\n", method.to_uppercase(), path).into_bytes();
+        for header_name in self.header.tl_headers.split(',') {
+            let header_name = header_name.trim().to_lowercase();
+            if header_name.is_empty() {
+                continue;
+            }
+            let value = headers.get(&header_name).ok_or_else(|| {
+                Error::Other(anyhow!(
+                    "signed header `{header_name}` is missing from the request"
+                ))
+            })?;
+            payload.extend_from_slice(format!("{header_name}: {value}\n").as_bytes());
+        }
+        payload.extend_from_slice(body);
+
+        let mut signing_input = self.header_b64.clone().into_bytes();
+        signing_input.push(b'.');
+        signing_input.extend_from_slice(URL_SAFE_NO_PAD.encode(&payload).as_bytes());
+        Ok(signing_input)
+    }
+
+    pub fn verify(&self, key: &VerifyingKey, signing_input: &[u8]) -> Result<(), Error> {
+        let signature = Signature::from_slice(&self.signature)
+            .map_err(|e| Error::Other(anyhow!("Tl-Signature signature is malformed: {e}")))?;
+        key.verify(signing_input, &signature)
+            .map_err(|_| Error::Other(anyhow!("Tl-Signature verification failed")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p521::ecdsa::{signature::Signer, SigningKey};
+
+    fn signed_fixture(
+        signing_key: &SigningKey,
+        method: &str,
+        path: &str,
+        headers: &HashMap<String, String>,
+        body: &[u8],
+    ) -> String {
+        let header = JwsHeader {
+            alg: "ES512".to_string(),
+            kid: "test-kid".to_string(),
+            tl_version: "2".to_string(),
+            tl_headers: "Idempotency-Key".to_string(),
+        };
+        let header_b64 =
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&serde_json::json!({
+                "alg": header.alg,
+                "kid": header.kid,
+                "tl_version": header.tl_version,
+                "tl_headers": header.tl_headers,
+            }))
+            .unwrap());
+        let jws = DetachedJws {
+            header,
+            header_b64,
+            signature: Vec::new(),
+        };
+        let signing_input = jws.signing_input(method, path, headers, body).unwrap();
+        let signature: Signature = signing_key.sign(&signing_input);
+
+        format!(
+            "{}..{}",
+            jws.header_b64,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        )
+    }
+
+    #[test]
+    fn round_trips_a_valid_signature() {
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let headers = HashMap::from([("idempotency-key".to_string(), "abc123".to_string())]);
+        let body = br#"{"event_type":"payment_executed"}"#;
+
+        let signature_header = signed_fixture(&signing_key, "POST", "/webhooks/payments", &headers, body);
+
+        let jws = DetachedJws::parse(&signature_header).unwrap();
+        assert_eq!(jws.header.alg, "ES512");
+        let signing_input = jws
+            .signing_input("POST", "/webhooks/payments", &headers, body)
+            .unwrap();
+        jws.verify(&verifying_key, &signing_input).unwrap();
+    }
+
+    #[test]
+    fn rejects_a_tampered_body() {
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let headers = HashMap::from([("idempotency-key".to_string(), "abc123".to_string())]);
+        let body = br#"{"event_type":"payment_executed"}"#;
+
+        let signature_header = signed_fixture(&signing_key, "POST", "/webhooks/payments", &headers, body);
+
+        let jws = DetachedJws::parse(&signature_header).unwrap();
+        let tampered_body = br#"{"event_type":"payment_failed"}"#;
+        let signing_input = jws
+            .signing_input("POST", "/webhooks/payments", &headers, tampered_body)
+            .unwrap();
+        assert!(jws.verify(&verifying_key, &signing_input).is_err());
+    }
+
+    #[test]
+    fn rejects_an_unknown_header_casing() {
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        let signed_headers = HashMap::from([("idempotency-key".to_string(), "abc123".to_string())]);
+        // The value is looked up with the lower-cased header name, regardless
+        // of the casing the `tl_headers` claim or caller used.
+        let headers = HashMap::from([("Idempotency-Key".to_string(), "abc123".to_string())]);
+        let body = b"{}";
+
+        let signature_header =
+            signed_fixture(&signing_key, "POST", "/webhooks/payments", &signed_headers, body);
+        let jws = DetachedJws::parse(&signature_header).unwrap();
+
+        assert!(jws
+            .signing_input("POST", "/webhooks/payments", &headers, body)
+            .is_err());
+    }
+}
@@ -0,0 +1,230 @@
+mod jws;
+mod model;
+
+pub use model::PaymentWebhookEvent;
+
+use crate::Error;
+use anyhow::anyhow;
+use jws::DetachedJws;
+use p521::ecdsa::VerifyingKey;
+use std::collections::HashMap;
+
+/// The header TrueLayer signs payment webhook payloads with.
+pub const SIGNATURE_HEADER: &str = "Tl-Signature";
+
+/// A cached set of TrueLayer's webhook signing keys, keyed by `kid`.
+#[derive(Debug, Clone, Default)]
+pub struct Jwks {
+    keys: HashMap<String, VerifyingKey>,
+}
+
+impl Jwks {
+    /// Builds a [`Jwks`] from the raw JSON returned by TrueLayer's JWKS endpoint.
+    pub fn from_json(jwks_json: &str) -> Result<Self, Error> {
+        #[derive(serde::Deserialize)]
+        struct Jwk {
+            kid: String,
+            x: String,
+            y: String,
+        }
+        #[derive(serde::Deserialize)]
+        struct JwkSet {
+            keys: Vec<Jwk>,
+        }
+
+        let JwkSet { keys } = serde_json::from_str(jwks_json)
+            .map_err(|e| Error::Other(anyhow!("invalid JWKS document: {e}")))?;
+
+        let keys = keys
+            .into_iter()
+            .map(|jwk| {
+                let key = ec_public_key_from_coordinates(&jwk.x, &jwk.y)?;
+                Ok((jwk.kid, key))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self { keys })
+    }
+
+    fn get(&self, kid: &str) -> Result<&VerifyingKey, Error> {
+        self.keys
+            .get(kid)
+            .ok_or_else(|| Error::Other(anyhow!("no JWKS key found for kid `{kid}`")))
+    }
+}
+
+/// The length, in bytes, of a P-521 field element — the size `EncodedPoint`
+/// requires each coordinate to be.
+const P521_COORDINATE_LEN: usize = 66;
+
+fn ec_public_key_from_coordinates(x: &str, y: &str) -> Result<VerifyingKey, Error> {
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use p521::EncodedPoint;
+
+    let x = URL_SAFE_NO_PAD
+        .decode(x)
+        .map_err(|e| Error::Other(anyhow!("JWKS key has an invalid `x` coordinate: {e}")))?;
+    let y = URL_SAFE_NO_PAD
+        .decode(y)
+        .map_err(|e| Error::Other(anyhow!("JWKS key has an invalid `y` coordinate: {e}")))?;
+    // `EncodedPoint::from_affine_coordinates` takes each coordinate as a
+    // fixed-size `GenericArray`, and the `.into()` conversions below go through
+    // a panicking `From<&[u8]>`, so the lengths must be checked up front rather
+    // than relying on that conversion.
+    if x.len() != P521_COORDINATE_LEN || y.len() != P521_COORDINATE_LEN {
+        return Err(Error::Other(anyhow!(
+            "JWKS key coordinates must be {P521_COORDINATE_LEN} bytes each, got x={}, y={}",
+            x.len(),
+            y.len()
+        )));
+    }
+    let point = EncodedPoint::from_affine_coordinates(x.as_slice().into(), y.as_slice().into(), false);
+    VerifyingKey::from_encoded_point(&point)
+        .map_err(|e| Error::Other(anyhow!("JWKS key is not a valid P-521 point: {e}")))
+}
+
+/// Verifies a `Tl-Signature` header against the raw request and returns the
+/// typed event it signs.
+///
+/// `path` is the request path the webhook was delivered to (e.g.
+/// `/webhooks/payments`) and `headers` must contain every header named in
+/// the JWS's `tl_headers` claim, with lower-cased names.
+pub fn verify_payment_webhook(
+    body: &[u8],
+    signature_header: &str,
+    method: &str,
+    path: &str,
+    headers: &HashMap<String, String>,
+    jwks: &Jwks,
+) -> Result<PaymentWebhookEvent, Error> {
+    let jws = DetachedJws::parse(signature_header)?;
+    let key = jwks.get(&jws.header.kid)?;
+    let signing_input = jws.signing_input(method, path, headers, body)?;
+    jws.verify(key, &signing_input)?;
+
+    serde_json::from_slice(body)
+        .map_err(|e| Error::Other(anyhow!("webhook body is not a valid payment event: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use p521::{
+        ecdsa::{signature::Signer, Signature, SigningKey},
+        elliptic_curve::sec1::ToEncodedPoint,
+    };
+
+    fn jwks_json_for(signing_key: &SigningKey, kid: &str) -> String {
+        let point = VerifyingKey::from(signing_key).to_encoded_point(false);
+        let x = URL_SAFE_NO_PAD.encode(point.x().unwrap());
+        let y = URL_SAFE_NO_PAD.encode(point.y().unwrap());
+        format!(r#"{{"keys":[{{"kid":"{kid}","x":"{x}","y":"{y}"}}]}}"#)
+    }
+
+    fn sign_webhook(
+        signing_key: &SigningKey,
+        kid: &str,
+        method: &str,
+        path: &str,
+        headers: &HashMap<String, String>,
+        body: &[u8],
+    ) -> String {
+        let header = serde_json::json!({
+            "alg": "ES512",
+            "kid": kid,
+            "tl_version": "2",
+            "tl_headers": "",
+        });
+        let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header).unwrap());
+
+        let mut payload = format!("{}\n{}\n", method.to_uppercase(), path).into_bytes();
+        for (name, value) in headers {
+            payload.extend_from_slice(format!("{name}: {value}\n").as_bytes());
+        }
+        payload.extend_from_slice(body);
+
+        let mut signing_input = header_b64.clone().into_bytes();
+        signing_input.push(b'.');
+        signing_input.extend_from_slice(URL_SAFE_NO_PAD.encode(&payload).as_bytes());
+
+        let signature: Signature = signing_key.sign(&signing_input);
+        format!("{header_b64}..{}", URL_SAFE_NO_PAD.encode(signature.to_bytes()))
+    }
+
+    #[test]
+    fn verifies_a_genuine_webhook_and_parses_the_event() {
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        let jwks = Jwks::from_json(&jwks_json_for(&signing_key, "kid-1")).unwrap();
+        let headers = HashMap::new();
+        let body = br#"{"event_type":"payment_executed","payment_id":"p1","executed_at":"2024-01-01T00:00:00Z"}"#;
+        let signature_header =
+            sign_webhook(&signing_key, "kid-1", "POST", "/webhooks/payments", &headers, body);
+
+        let event = verify_payment_webhook(
+            body,
+            &signature_header,
+            "POST",
+            "/webhooks/payments",
+            &headers,
+            &jwks,
+        )
+        .unwrap();
+
+        assert!(matches!(event, PaymentWebhookEvent::PaymentExecuted { .. }));
+    }
+
+    #[test]
+    fn rejects_an_unknown_kid() {
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        let other_key = SigningKey::random(&mut rand::thread_rng());
+        let jwks = Jwks::from_json(&jwks_json_for(&other_key, "kid-2")).unwrap();
+        let headers = HashMap::new();
+        let body = b"{}";
+        let signature_header =
+            sign_webhook(&signing_key, "kid-1", "POST", "/webhooks/payments", &headers, body);
+
+        let result = verify_payment_webhook(
+            body,
+            &signature_header,
+            "POST",
+            "/webhooks/payments",
+            &headers,
+            &jwks,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_an_unsupported_algorithm() {
+        let header = serde_json::json!({
+            "alg": "RS256",
+            "kid": "kid-1",
+            "tl_version": "2",
+            "tl_headers": "",
+        });
+        let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header).unwrap());
+        let signature_header = format!("{header_b64}..");
+
+        let result = verify_payment_webhook(
+            b"{}",
+            &signature_header,
+            "POST",
+            "/webhooks/payments",
+            &HashMap::new(),
+            &Jwks::default(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn jwks_from_json_rejects_a_malformed_coordinate_instead_of_panicking() {
+        // "AAAA" base64-decodes to 3 bytes, nowhere near the 66 a P-521
+        // coordinate requires — this must error, not panic.
+        let jwks_json = r#"{"keys":[{"kid":"kid-1","x":"AAAA","y":"AAAA"}]}"#;
+
+        assert!(Jwks::from_json(jwks_json).is_err());
+    }
+}
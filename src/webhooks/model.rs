@@ -0,0 +1,26 @@
+use crate::apis::payments::model::{FailureStage, PaymentSource};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A payment status change delivered as a signed webhook.
+///
+/// Reuses the same status vocabulary as [`PaymentStatus`](crate::apis::payments::model::PaymentStatus).
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(tag = "event_type", rename_all = "snake_case")]
+pub enum PaymentWebhookEvent {
+    PaymentExecuted {
+        payment_id: String,
+        executed_at: DateTime<Utc>,
+    },
+    PaymentSettled {
+        payment_id: String,
+        payment_source: PaymentSource,
+        settled_at: DateTime<Utc>,
+    },
+    PaymentFailed {
+        payment_id: String,
+        failed_at: DateTime<Utc>,
+        failure_stage: FailureStage,
+        failure_reason: String,
+    },
+}